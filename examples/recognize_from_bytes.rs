@@ -1,8 +1,16 @@
 // 从字节数组识别示例
 
+#[cfg(windows)]
 use qcocr::recognize_from_bytes;
+#[cfg(windows)]
 use std::fs;
 
+#[cfg(not(windows))]
+fn main() {
+    eprintln!("此示例依赖 Windows OCR 引擎，仅能在 Windows 上运行");
+}
+
+#[cfg(windows)]
 fn main() {
     let args: Vec<String> = std::env::args().collect();
     
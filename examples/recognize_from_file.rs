@@ -1,8 +1,15 @@
 // 从文件路径识别示例
 // 演示基本的 OCR 识别功能，包括文本、位置、间距等信息
 
+#[cfg(windows)]
 use qcocr::recognize_from_file;
 
+#[cfg(not(windows))]
+fn main() {
+    eprintln!("此示例依赖 Windows OCR 引擎，仅能在 Windows 上运行");
+}
+
+#[cfg(windows)]
 fn main() {
     let args: Vec<String> = std::env::args().collect();
     
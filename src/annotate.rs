@@ -0,0 +1,146 @@
+use ab_glyph::{FontRef, PxScale};
+use imageproc::drawing::{draw_line_segment_mut, draw_text_mut};
+use imageproc::image::{Rgba, RgbaImage};
+
+use crate::{BoundingBox, DecodedImage, OcrRecognitionResult};
+
+/// `render_annotated` 标注的粒度
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnnotateLevel {
+    /// 每个 `OcrLine` 画一个框
+    Line,
+    /// 每个 `OcrWord` 画一个框
+    Word,
+}
+
+/// `render_annotated` 的绘制选项
+pub struct AnnotateOptions {
+    /// 按行还是按词画框
+    pub level: AnnotateLevel,
+    /// 是否在框旁边绘制识别出的文字
+    pub draw_text: bool,
+    /// 绘制文字时使用的 TrueType/OpenType 字体数据；`draw_text` 为 `true` 时必须提供
+    pub font_bytes: Option<Vec<u8>>,
+    /// 文字的像素大小
+    pub font_scale: f32,
+    /// 边框颜色（RGB）
+    pub box_color: (u8, u8, u8),
+    /// 文字颜色（RGB）
+    pub text_color: (u8, u8, u8),
+}
+
+impl Default for AnnotateOptions {
+    fn default() -> Self {
+        Self {
+            level: AnnotateLevel::Line,
+            draw_text: false,
+            font_bytes: None,
+            font_scale: 16.0,
+            box_color: (255, 0, 0),
+            text_color: (0, 0, 0),
+        }
+    }
+}
+
+/// 把识别结果的每个矩形框（以及可选的文字）绘制回原图上，编码为 PNG 字节
+///
+/// 框选粒度由 `options.level` 决定；当 `result.text_angle` 有值时，每个框会绕自身
+/// 中心旋转该角度后再绘制，以贴合倾斜画面中的文字方向
+pub fn render_annotated(
+    image: &DecodedImage,
+    result: &OcrRecognitionResult,
+    options: &AnnotateOptions,
+) -> Result<Vec<u8>, String> {
+    if options.draw_text && options.font_bytes.is_none() {
+        return Err("draw_text 为 true 时必须提供 font_bytes".to_string());
+    }
+
+    let mut canvas = RgbaImage::from_raw(image.width, image.height, image.pixels.clone())
+        .ok_or_else(|| "像素缓冲区大小与宽高不匹配".to_string())?;
+
+    let font = options
+        .font_bytes
+        .as_deref()
+        .map(FontRef::try_from_slice)
+        .transpose()
+        .map_err(|e| format!("字体加载失败: {:?}", e))?;
+
+    let box_color = Rgba([options.box_color.0, options.box_color.1, options.box_color.2, 255]);
+    let text_color = Rgba([options.text_color.0, options.text_color.1, options.text_color.2, 255]);
+    let angle = result.text_angle.unwrap_or(0.0);
+
+    for line in &result.lines {
+        match options.level {
+            AnnotateLevel::Line => {
+                draw_box(&mut canvas, &line.bounds, angle, box_color);
+                if options.draw_text {
+                    draw_label(&mut canvas, &line.bounds, &line.text, font.as_ref(), options.font_scale, text_color);
+                }
+            }
+            AnnotateLevel::Word => {
+                for word in &line.words {
+                    draw_box(&mut canvas, &word.bounds, angle, box_color);
+                    if options.draw_text {
+                        draw_label(&mut canvas, &word.bounds, &word.text, font.as_ref(), options.font_scale, text_color);
+                    }
+                }
+            }
+        }
+    }
+
+    let annotated = DecodedImage {
+        width: canvas.width(),
+        height: canvas.height(),
+        pixels: canvas.into_raw(),
+    };
+    annotated.to_png_bytes()
+}
+
+/// 绕矩形自身中心旋转 `angle_degrees` 度后，依次连接四个角画出边框
+fn draw_box(canvas: &mut RgbaImage, bounds: &BoundingBox, angle_degrees: f64, color: Rgba<u8>) {
+    let corners = rotated_corners(bounds, angle_degrees);
+    for i in 0..4 {
+        let (x0, y0) = corners[i];
+        let (x1, y1) = corners[(i + 1) % 4];
+        draw_line_segment_mut(canvas, (x0, y0), (x1, y1), color);
+    }
+}
+
+fn rotated_corners(bounds: &BoundingBox, angle_degrees: f64) -> [(f32, f32); 4] {
+    let (x0, y0) = (bounds.x, bounds.y);
+    let (x1, y1) = (bounds.x + bounds.width, bounds.y + bounds.height);
+    let corners = [(x0, y0), (x1, y0), (x1, y1), (x0, y1)];
+
+    if angle_degrees == 0.0 {
+        return corners;
+    }
+
+    let (cx, cy) = (bounds.x + bounds.width / 2.0, bounds.y + bounds.height / 2.0);
+    let angle = (angle_degrees as f32).to_radians();
+    let (sin_a, cos_a) = (angle.sin(), angle.cos());
+
+    corners.map(|(x, y)| {
+        let dx = x - cx;
+        let dy = y - cy;
+        (cx + dx * cos_a - dy * sin_a, cy + dx * sin_a + dy * cos_a)
+    })
+}
+
+fn draw_label(
+    canvas: &mut RgbaImage,
+    bounds: &BoundingBox,
+    text: &str,
+    font: Option<&FontRef>,
+    font_scale: f32,
+    color: Rgba<u8>,
+) {
+    let Some(font) = font else { return };
+    if text.is_empty() {
+        return;
+    }
+
+    // 文字画在框的正上方，贴着框顶边
+    let x = bounds.x.round() as i32;
+    let y = (bounds.y - font_scale).round() as i32;
+    draw_text_mut(canvas, color, x, y, PxScale::from(font_scale), font, text);
+}
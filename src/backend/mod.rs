@@ -0,0 +1,27 @@
+mod ppocr;
+#[cfg(windows)]
+mod windows_backend;
+
+pub use ppocr::{PpOcrBackend, PpOcrModelPaths};
+#[cfg(windows)]
+pub use windows_backend::WindowsOcrBackend;
+
+use crate::{DecodedImage, OcrRecognitionResult};
+
+/// 可插拔的 OCR 识别后端
+///
+/// 默认实现 [`WindowsOcrBackend`] 封装系统自带的 `windows::Media::Ocr`，
+/// 仅能在 Windows 上使用；[`PpOcrBackend`] 通过 ONNX Runtime 运行 PP-OCR
+/// 风格的检测 + 方向分类 + 识别三段式流水线，不依赖 Windows 运行时
+pub trait OcrBackend {
+    /// 对已解码的位图执行一次完整识别
+    ///
+    /// # 参数
+    /// - `bitmap` - 已解码的位图
+    /// - `language` - 语言代码，`None` 表示使用后端默认语言
+    fn recognize(
+        &self,
+        bitmap: &DecodedImage,
+        language: Option<&str>,
+    ) -> Result<OcrRecognitionResult, String>;
+}
@@ -0,0 +1,561 @@
+use std::fs;
+use std::sync::Mutex;
+
+use ort::session::{builder::GraphOptimizationLevel, Session};
+use ort::value::Tensor;
+
+use crate::{BoundingBox, DecodedImage, OcrBackend, OcrLine, OcrRecognitionResult, OcrWord};
+
+/// DBNet 检测阶段：长边缩放到不超过该值，再向上取整到 32 的倍数（网络要求输入尺寸是步长的整数倍）
+const DET_MAX_SIDE: u32 = 960;
+const DET_SIDE_MULTIPLE: u32 = 32;
+const DET_MEAN: [f32; 3] = [0.485, 0.456, 0.406];
+const DET_STD: [f32; 3] = [0.229, 0.224, 0.225];
+
+/// 方向分类模型固定输入尺寸，与 PP-OCR cls 模型一致
+const CLS_WIDTH: u32 = 192;
+const CLS_HEIGHT: u32 = 48;
+
+/// CRNN 识别模型固定高度，宽度按原图长宽比缩放
+const REC_HEIGHT: u32 = 32;
+
+/// 方向分类 / 识别模型统一使用 `(像素/255 - 0.5) / 0.5` 归一化到 [-1, 1]
+const UNIT_MEAN: [f32; 3] = [0.5, 0.5, 0.5];
+const UNIT_STD: [f32; 3] = [0.5, 0.5, 0.5];
+
+/// PP-OCR 三段式流水线所需的模型文件路径
+pub struct PpOcrModelPaths {
+    /// 文字检测模型（DB/DBNet，输出每像素的文字概率图）
+    pub detection_model: String,
+    /// 方向分类模型，传 `None` 跳过 180° 旋转检测
+    pub angle_classifier_model: Option<String>,
+    /// 文字识别模型（CRNN + CTC）
+    pub recognition_model: String,
+    /// 识别字典文件，每行一个字符，行号对应 CTC 输出类别（0 号为 blank）
+    pub dictionary: String,
+}
+
+/// 基于 ONNX Runtime 的跨平台 OCR 后端，运行 PP-OCR 风格的检测 -> 方向分类 -> 识别流水线
+///
+/// `Session::run` 需要独占访问，而 [`OcrBackend::recognize`] 只接收 `&self`，
+/// 所以每个模型会话都用 `Mutex` 包一层，推理时临时取得独占锁
+pub struct PpOcrBackend {
+    detection: Mutex<Session>,
+    angle_classifier: Option<Mutex<Session>>,
+    recognition: Mutex<Session>,
+    charset: Vec<String>,
+}
+
+/// 检测阶段输出的概率图，`width`/`height` 与送入检测网络的缩放图一致
+struct ProbabilityMap {
+    width: usize,
+    height: usize,
+    values: Vec<f32>,
+}
+
+/// 检测到的一个文字区域及其最小外接旋转矩形
+struct TextRegion {
+    /// 四个角点，顺序沿矩形边按顺时针排列
+    corners: [(f32, f32); 4],
+}
+
+impl PpOcrBackend {
+    /// 加载检测 / 方向分类 / 识别三个模型以及识别字典
+    pub fn new(paths: PpOcrModelPaths) -> Result<Self, String> {
+        let detection = build_session(&paths.detection_model)?;
+        let angle_classifier = paths
+            .angle_classifier_model
+            .as_deref()
+            .map(build_session)
+            .transpose()?;
+        let recognition = build_session(&paths.recognition_model)?;
+
+        let dict_text =
+            fs::read_to_string(&paths.dictionary).map_err(|e| format!("字典文件读取失败: {}", e))?;
+        let mut charset: Vec<String> = vec!["".to_string()]; // 索引 0 为 CTC blank
+        charset.extend(dict_text.lines().map(|l| l.to_string()));
+
+        Ok(Self {
+            detection: Mutex::new(detection),
+            angle_classifier: angle_classifier.map(Mutex::new),
+            recognition: Mutex::new(recognition),
+            charset,
+        })
+    }
+
+    fn detect(&self, bitmap: &DecodedImage) -> Result<ProbabilityMap, String> {
+        run_detection_model(&self.detection, bitmap)
+    }
+
+    fn is_upside_down(&self, crop: &DecodedImage) -> Result<bool, String> {
+        match &self.angle_classifier {
+            Some(session) => run_angle_classifier(session, crop),
+            None => Ok(false),
+        }
+    }
+
+    fn recognize_crop(&self, crop: &DecodedImage) -> Result<String, String> {
+        let logits = run_recognition_model(&self.recognition, crop)?;
+        Ok(ctc_greedy_decode(&logits, &self.charset))
+    }
+}
+
+impl OcrBackend for PpOcrBackend {
+    fn recognize(
+        &self,
+        bitmap: &DecodedImage,
+        _language: Option<&str>,
+    ) -> Result<OcrRecognitionResult, String> {
+        let prob_map = self.detect(bitmap)?;
+        let mask = binarize(&prob_map, 0.3);
+        let components = find_connected_components(&mask, prob_map.width, prob_map.height);
+
+        let scale_x = bitmap.width as f32 / prob_map.width as f32;
+        let scale_y = bitmap.height as f32 / prob_map.height as f32;
+
+        let mut lines = Vec::new();
+        let mut full_text = String::new();
+
+        for component in components {
+            let region = min_area_rect(&component, scale_x, scale_y);
+            let rect = region.to_bounding_box();
+            if rect.width < 1.0 || rect.height < 1.0 {
+                continue;
+            }
+
+            let mut crop = match crop_rotated(bitmap, &region) {
+                Some(crop) => crop,
+                None => continue,
+            };
+            if self.is_upside_down(&crop)? {
+                crop = rotate_180(&crop);
+            }
+
+            let text = self.recognize_crop(&crop)?;
+            if text.is_empty() {
+                continue;
+            }
+
+            full_text.push_str(&text);
+            full_text.push('\n');
+
+            let words = text
+                .split_whitespace()
+                .map(|word| OcrWord {
+                    text: word.to_string(),
+                    bounds: rect.clone(),
+                })
+                .collect();
+
+            lines.push(OcrLine {
+                text,
+                bounds: rect,
+                words,
+            });
+        }
+
+        Ok(OcrRecognitionResult {
+            lines,
+            text: full_text.trim().to_string(),
+            text_angle: None,
+        })
+    }
+}
+
+impl TextRegion {
+    fn to_bounding_box(&self) -> BoundingBox {
+        let xs = self.corners.iter().map(|c| c.0);
+        let ys = self.corners.iter().map(|c| c.1);
+        let min_x = xs.clone().fold(f32::MAX, f32::min);
+        let max_x = xs.fold(f32::MIN, f32::max);
+        let min_y = ys.clone().fold(f32::MAX, f32::min);
+        let max_y = ys.fold(f32::MIN, f32::max);
+
+        BoundingBox {
+            x: min_x,
+            y: min_y,
+            width: max_x - min_x,
+            height: max_y - min_y,
+        }
+    }
+}
+
+fn build_session(model_path: &str) -> Result<Session, String> {
+    Session::builder()
+        .map_err(|e| format!("ONNX Runtime 初始化失败: {}", e))?
+        .with_optimization_level(GraphOptimizationLevel::Level3)
+        .map_err(|e| format!("ONNX Runtime 配置失败: {}", e))?
+        .commit_from_file(model_path)
+        .map_err(|e| format!("模型加载失败 ({}): {}", model_path, e))
+}
+
+/// 在检测模型输入尺寸下运行 DB/DBNet，返回每像素的文字概率
+///
+/// PP-OCR 导出的检测模型自带 Sigmoid 输出层，`det_db_thresh` 等阈值也是按该输出
+/// 已经是 [0, 1] 概率值来标定的，这里直接对其阈值化，不再额外做一次 Sigmoid
+fn run_detection_model(session: &Mutex<Session>, bitmap: &DecodedImage) -> Result<ProbabilityMap, String> {
+    let (target_w, target_h) = detection_input_size(bitmap.width, bitmap.height);
+    let resized = bitmap.resize(target_w, target_h);
+    let input = to_nchw(&resized, DET_MEAN, DET_STD);
+
+    let (shape, values) = run_inference(session, target_w as i64, target_h as i64, input)?;
+
+    // 输出形状为 [N, C, H, W]，概率图取最后两维
+    let height = *shape.get(shape.len().wrapping_sub(2)).unwrap_or(&(target_h as i64)) as usize;
+    let width = *shape.last().unwrap_or(&(target_w as i64)) as usize;
+
+    if width.saturating_mul(height) != values.len() {
+        return Err(format!(
+            "检测模型输出形状与数据长度不一致: {}x{} != {}",
+            width,
+            height,
+            values.len()
+        ));
+    }
+
+    Ok(ProbabilityMap { width, height, values })
+}
+
+/// 对裁剪出的文字区域运行方向分类模型，返回是否判定为 180° 旋转
+fn run_angle_classifier(session: &Mutex<Session>, crop: &DecodedImage) -> Result<bool, String> {
+    let resized = crop.resize(CLS_WIDTH, CLS_HEIGHT);
+    let input = to_nchw(&resized, UNIT_MEAN, UNIT_STD);
+
+    let (_, data) = run_inference(session, CLS_WIDTH as i64, CLS_HEIGHT as i64, input)?;
+
+    // 输出是 2 类得分：索引 0 对应 0°，索引 1 对应 180°
+    Ok(data.len() >= 2 && data[1] > data[0])
+}
+
+/// 对裁剪并按高度归一化后的文字区域运行 CRNN，返回按时间步排列的字符概率（CTC 输入）
+fn run_recognition_model(session: &Mutex<Session>, crop: &DecodedImage) -> Result<Vec<Vec<f32>>, String> {
+    if crop.width == 0 || crop.height == 0 {
+        return Ok(Vec::new());
+    }
+
+    let target_width = ((REC_HEIGHT as f32 * crop.width as f32 / crop.height as f32).round() as u32).max(1);
+    let resized = crop.resize(target_width, REC_HEIGHT);
+    let input = to_nchw(&resized, UNIT_MEAN, UNIT_STD);
+
+    let (shape, data) = run_inference(session, target_width as i64, REC_HEIGHT as i64, input)?;
+    Ok(unflatten_recognition_output(&shape, &data))
+}
+
+/// 计算 DBNet 输入尺寸：长边缩放到不超过 `DET_MAX_SIDE`，再向上取整到 32 的倍数
+fn detection_input_size(width: u32, height: u32) -> (u32, u32) {
+    let longer = width.max(height).max(1);
+    let scale = if longer > DET_MAX_SIDE { DET_MAX_SIDE as f32 / longer as f32 } else { 1.0 };
+
+    let scaled_w = ((width as f32 * scale).round() as u32).max(1);
+    let scaled_h = ((height as f32 * scale).round() as u32).max(1);
+
+    (round_up(scaled_w, DET_SIDE_MULTIPLE), round_up(scaled_h, DET_SIDE_MULTIPLE))
+}
+
+fn round_up(value: u32, multiple: u32) -> u32 {
+    value.div_ceil(multiple) * multiple
+}
+
+/// 把 RGBA 图像转换成 `[1, 3, H, W]` 的归一化 NCHW 浮点张量
+fn to_nchw(image: &DecodedImage, mean: [f32; 3], std: [f32; 3]) -> Vec<f32> {
+    let (width, height) = (image.width as usize, image.height as usize);
+    let mut data = vec![0f32; 3 * width * height];
+
+    for y in 0..height {
+        for x in 0..width {
+            let pixel = (y * width + x) * 4;
+            for c in 0..3 {
+                let value = image.pixels[pixel + c] as f32 / 255.0;
+                data[c * width * height + y * width + x] = (value - mean[c]) / std[c];
+            }
+        }
+    }
+
+    data
+}
+
+/// 锁定会话、以第一个输入名送入 NCHW 张量执行推理，返回第一个输出的形状和数据
+fn run_inference(
+    session: &Mutex<Session>,
+    width: i64,
+    height: i64,
+    data: Vec<f32>,
+) -> Result<(Vec<i64>, Vec<f32>), String> {
+    let mut session = session.lock().map_err(|_| "ONNX Runtime 会话已被污染".to_string())?;
+    let input_name = session.inputs()[0].name().to_string();
+
+    let tensor = Tensor::from_array(([1i64, 3, height, width], data))
+        .map_err(|e| format!("构造输入张量失败: {}", e))?;
+    let outputs = session
+        .run(ort::inputs![input_name.as_str() => tensor])
+        .map_err(|e| format!("模型推理失败: {}", e))?;
+
+    let (shape, values) = outputs[0]
+        .try_extract_tensor::<f32>()
+        .map_err(|e| format!("输出解析失败: {}", e))?;
+
+    Ok((shape.to_vec(), values.to_vec()))
+}
+
+/// CRNN 的 ONNX 导出常见 `[T, 1, C]` 或 `[1, T, C]` 两种排布，但两者的底层数据都是
+/// 按时间步连续排列的 `C` 维向量，因此只需按最后一维（类别数）切块即可还原
+fn unflatten_recognition_output(shape: &[i64], data: &[f32]) -> Vec<Vec<f32>> {
+    let Some(&classes) = shape.last() else {
+        return Vec::new();
+    };
+    let classes = classes as usize;
+
+    if classes == 0 || !data.len().is_multiple_of(classes) {
+        return Vec::new();
+    }
+
+    data.chunks_exact(classes).map(|step| step.to_vec()).collect()
+}
+
+fn binarize(prob_map: &ProbabilityMap, threshold: f32) -> Vec<bool> {
+    prob_map.values.iter().map(|&p| p > threshold).collect()
+}
+
+/// 4-连通区域生长，返回每个连通分量包含的像素坐标
+fn find_connected_components(mask: &[bool], width: usize, height: usize) -> Vec<Vec<(usize, usize)>> {
+    let mut visited = vec![false; mask.len()];
+    let mut components = Vec::new();
+
+    for y in 0..height {
+        for x in 0..width {
+            let idx = y * width + x;
+            if !mask[idx] || visited[idx] {
+                continue;
+            }
+
+            let mut stack = vec![(x, y)];
+            let mut component = Vec::new();
+            visited[idx] = true;
+
+            while let Some((cx, cy)) = stack.pop() {
+                component.push((cx, cy));
+
+                let neighbors = [
+                    (cx.wrapping_sub(1), cy),
+                    (cx + 1, cy),
+                    (cx, cy.wrapping_sub(1)),
+                    (cx, cy + 1),
+                ];
+
+                for (nx, ny) in neighbors {
+                    if nx >= width || ny >= height {
+                        continue;
+                    }
+                    let nidx = ny * width + nx;
+                    if mask[nidx] && !visited[nidx] {
+                        visited[nidx] = true;
+                        stack.push((nx, ny));
+                    }
+                }
+            }
+
+            components.push(component);
+        }
+    }
+
+    components
+}
+
+/// 通过凸包 + 旋转卡壳求连通分量的最小面积外接矩形，并按比例映射回原图坐标
+fn min_area_rect(component: &[(usize, usize)], scale_x: f32, scale_y: f32) -> TextRegion {
+    let points: Vec<(f32, f32)> = component
+        .iter()
+        .map(|&(x, y)| (x as f32 * scale_x, y as f32 * scale_y))
+        .collect();
+
+    let hull = convex_hull(&points);
+    if hull.len() < 3 {
+        let (min_x, max_x, min_y, max_y) = bounds(&points);
+        return TextRegion {
+            corners: [
+                (min_x, min_y),
+                (max_x, min_y),
+                (max_x, max_y),
+                (min_x, max_y),
+            ],
+        };
+    }
+
+    let mut best_area = f32::MAX;
+    let mut best_corners = [(0.0, 0.0); 4];
+
+    for i in 0..hull.len() {
+        let (x1, y1) = hull[i];
+        let (x2, y2) = hull[(i + 1) % hull.len()];
+        let edge_angle = (y2 - y1).atan2(x2 - x1);
+
+        let (cos_a, sin_a) = (edge_angle.cos(), edge_angle.sin());
+        let rotated: Vec<(f32, f32)> = hull
+            .iter()
+            .map(|&(x, y)| (x * cos_a + y * sin_a, -x * sin_a + y * cos_a))
+            .collect();
+
+        let (min_x, max_x, min_y, max_y) = bounds(&rotated);
+        let area = (max_x - min_x) * (max_y - min_y);
+
+        if area < best_area {
+            best_area = area;
+            let local = [
+                (min_x, min_y),
+                (max_x, min_y),
+                (max_x, max_y),
+                (min_x, max_y),
+            ];
+            best_corners = local.map(|(x, y)| (x * cos_a - y * sin_a, x * sin_a + y * cos_a));
+        }
+    }
+
+    TextRegion {
+        corners: best_corners,
+    }
+}
+
+fn bounds(points: &[(f32, f32)]) -> (f32, f32, f32, f32) {
+    let min_x = points.iter().map(|p| p.0).fold(f32::MAX, f32::min);
+    let max_x = points.iter().map(|p| p.0).fold(f32::MIN, f32::max);
+    let min_y = points.iter().map(|p| p.1).fold(f32::MAX, f32::min);
+    let max_y = points.iter().map(|p| p.1).fold(f32::MIN, f32::max);
+    (min_x, max_x, min_y, max_y)
+}
+
+/// Andrew's monotone chain 凸包算法
+fn convex_hull(points: &[(f32, f32)]) -> Vec<(f32, f32)> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    sorted.dedup();
+
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let cross = |o: (f32, f32), a: (f32, f32), b: (f32, f32)| -> f32 {
+        (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+    };
+
+    let mut lower = Vec::new();
+    for &p in &sorted {
+        while lower.len() >= 2 && cross(lower[lower.len() - 2], lower[lower.len() - 1], p) <= 0.0 {
+            lower.pop();
+        }
+        lower.push(p);
+    }
+
+    let mut upper = Vec::new();
+    for &p in sorted.iter().rev() {
+        while upper.len() >= 2 && cross(upper[upper.len() - 2], upper[upper.len() - 1], p) <= 0.0 {
+            upper.pop();
+        }
+        upper.push(p);
+    }
+
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower
+}
+
+/// 沿 `region` 的最小外接旋转矩形裁剪出一张摆正的图片，而不是先落回轴对齐包围盒
+/// 再裁剪——后者会把矩形外的背景也裁进来，对旋转文字的识别尤其有害
+fn crop_rotated(image: &DecodedImage, region: &TextRegion) -> Option<DecodedImage> {
+    let [c0, c1, c2, c3] = region.corners;
+    let width = dist(c0, c1).round() as u32;
+    let height = dist(c1, c2).round() as u32;
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    let u_axis = (c1.0 - c0.0, c1.1 - c0.1);
+    let v_axis = (c3.0 - c0.0, c3.1 - c0.1);
+
+    let mut pixels = vec![0u8; (width * height * 4) as usize];
+    for dy in 0..height {
+        for dx in 0..width {
+            let u = (dx as f32 + 0.5) / width as f32;
+            let v = (dy as f32 + 0.5) / height as f32;
+            let src_x = c0.0 + u * u_axis.0 + v * v_axis.0;
+            let src_y = c0.1 + u * u_axis.1 + v * v_axis.1;
+
+            let dst_idx = ((dy * width + dx) * 4) as usize;
+            if let Some(pixel) = sample_bilinear(image, src_x, src_y) {
+                pixels[dst_idx..dst_idx + 4].copy_from_slice(&pixel);
+            }
+        }
+    }
+
+    Some(DecodedImage {
+        width,
+        height,
+        pixels,
+    })
+}
+
+fn dist(a: (f32, f32), b: (f32, f32)) -> f32 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+}
+
+fn sample_bilinear(image: &DecodedImage, x: f32, y: f32) -> Option<[u8; 4]> {
+    if x < 0.0 || y < 0.0 || x > (image.width - 1) as f32 || y > (image.height - 1) as f32 {
+        return None;
+    }
+
+    let x0 = x as u32;
+    let y0 = y as u32;
+    let x1 = (x0 + 1).min(image.width - 1);
+    let y1 = (y0 + 1).min(image.height - 1);
+    let fx = x - x0 as f32;
+    let fy = y - y0 as f32;
+
+    let mut out = [0u8; 4];
+    for (c, channel) in out.iter_mut().enumerate() {
+        let p00 = image.pixels[((y0 * image.width + x0) * 4) as usize + c] as f32;
+        let p10 = image.pixels[((y0 * image.width + x1) * 4) as usize + c] as f32;
+        let p01 = image.pixels[((y1 * image.width + x0) * 4) as usize + c] as f32;
+        let p11 = image.pixels[((y1 * image.width + x1) * 4) as usize + c] as f32;
+        let top = p00 * (1.0 - fx) + p10 * fx;
+        let bottom = p01 * (1.0 - fx) + p11 * fx;
+        *channel = (top * (1.0 - fy) + bottom * fy).round() as u8;
+    }
+    Some(out)
+}
+
+fn rotate_180(image: &DecodedImage) -> DecodedImage {
+    let mut pixels = image.pixels.clone();
+    pixels.reverse();
+    // 反转整条像素流后，每个像素内部的 4 个通道字节顺序也被反转了，这里转回来
+    for chunk in pixels.chunks_mut(4) {
+        chunk.reverse();
+    }
+
+    DecodedImage {
+        width: image.width,
+        height: image.height,
+        pixels,
+    }
+}
+
+/// CTC 贪婪解码：逐时间步取最大概率类别，合并相邻重复并丢弃 blank（索引 0）
+fn ctc_greedy_decode(logits: &[Vec<f32>], charset: &[String]) -> String {
+    let mut result = String::new();
+    let mut prev_index = None;
+
+    for step in logits {
+        let (index, _) = step
+            .iter()
+            .enumerate()
+            .fold((0usize, f32::MIN), |best, (i, &v)| if v > best.1 { (i, v) } else { best });
+
+        if index != 0 && Some(index) != prev_index {
+            if let Some(ch) = charset.get(index) {
+                result.push_str(ch);
+            }
+        }
+        prev_index = Some(index);
+    }
+
+    result
+}
@@ -0,0 +1,56 @@
+use windows::{
+    core::HSTRING,
+    Globalization::Language,
+    Graphics::Imaging::BitmapDecoder,
+    Media::Ocr::OcrEngine,
+    Storage::Streams::{DataWriter, InMemoryRandomAccessStream},
+};
+
+use crate::{convert_ocr_result, DecodedImage, OcrBackend, OcrRecognitionResult};
+
+/// 默认 OCR 后端，封装系统自带的 `windows::Media::Ocr::OcrEngine`
+pub struct WindowsOcrBackend;
+
+impl OcrBackend for WindowsOcrBackend {
+    fn recognize(
+        &self,
+        bitmap: &DecodedImage,
+        language: Option<&str>,
+    ) -> Result<OcrRecognitionResult, String> {
+        recognize_internal(bitmap, language).map_err(|e| format!("OCR 识别失败: {}", e))
+    }
+}
+
+fn recognize_internal(bitmap: &DecodedImage, language: Option<&str>) -> Result<OcrRecognitionResult, String> {
+    let png_bytes = bitmap.to_png_bytes()?;
+
+    windows_recognize_png(&png_bytes, language).map_err(|e| e.message())
+}
+
+fn windows_recognize_png(
+    png_bytes: &[u8],
+    language: Option<&str>,
+) -> windows::core::Result<OcrRecognitionResult> {
+    let stream = InMemoryRandomAccessStream::new()?;
+    let writer = DataWriter::CreateDataWriter(&stream)?;
+
+    writer.WriteBytes(png_bytes)?;
+    writer.StoreAsync()?.get()?;
+    writer.FlushAsync()?.get()?;
+
+    stream.Seek(0)?;
+
+    let decoder = BitmapDecoder::CreateAsync(&stream)?.get()?;
+    let win_bitmap = decoder.GetSoftwareBitmapAsync()?.get()?;
+
+    let engine = if let Some(lang) = language {
+        let language_obj = Language::CreateLanguage(&HSTRING::from(lang))?;
+        OcrEngine::TryCreateFromLanguage(&language_obj)?
+    } else {
+        OcrEngine::TryCreateFromUserProfileLanguages()?
+    };
+
+    let result = engine.RecognizeAsync(&win_bitmap)?.get()?;
+
+    convert_ocr_result(&result)
+}
@@ -0,0 +1,161 @@
+use crate::preprocess::{otsu_threshold, to_grayscale_values};
+use crate::DecodedImage;
+
+#[cfg(windows)]
+use crate::{OcrBackend, OcrRecognitionResult, WindowsOcrBackend};
+
+const WIDE_SEARCH_RANGE: f64 = 15.0;
+const WIDE_SEARCH_STEP: f64 = 0.5;
+const NARROW_SEARCH_RANGE: f64 = 3.0;
+const NARROW_SEARCH_STEP: f64 = 0.25;
+
+/// 对图片文件自动纠偏后再用默认的 Windows 引擎识别，识别结果的 `text_angle`
+/// 会被替换为本次实际施加的纠偏角度
+#[cfg(windows)]
+pub fn recognize_from_file_with_deskew(
+    image_path: &str,
+    language: Option<&str>,
+) -> Result<OcrRecognitionResult, String> {
+    let image = DecodedImage::from_file(image_path)?;
+    recognize_with_deskew_internal(&image, language)
+}
+
+/// 对字节数组图片自动纠偏后再用默认的 Windows 引擎识别
+#[cfg(windows)]
+pub fn recognize_from_bytes_with_deskew(
+    image_data: &[u8],
+    language: Option<&str>,
+) -> Result<OcrRecognitionResult, String> {
+    let image = DecodedImage::from_bytes(image_data)?;
+    recognize_with_deskew_internal(&image, language)
+}
+
+#[cfg(windows)]
+fn recognize_with_deskew_internal(
+    image: &DecodedImage,
+    language: Option<&str>,
+) -> Result<OcrRecognitionResult, String> {
+    let (upright, applied_angle) = deskew(image, None);
+    let mut result = WindowsOcrBackend.recognize(&upright, language)?;
+    result.text_angle = Some(applied_angle);
+    Ok(result)
+}
+
+/// 估计图片的倾斜角度并旋正，返回旋正后的图片以及本次施加的纠偏角度（度）
+///
+/// 通过投影轮廓估计倾斜角：先二值化，再对候选角度逐一旋转二值图、把每行前景
+/// 像素数累加成水平直方图、计算相邻行之间的方差（差的平方和）——文字行对齐越好，
+/// 这个直方图的峰谷越陡，方差也越大，取方差最大的角度作为倾斜角
+///
+/// # 参数
+/// - `angle_hint` - 来自识别引擎 `text_angle` 等途径的粗略角度估计，提供后会把
+///   候选搜索范围收窄到该角度附近，加快估计速度
+pub fn deskew(image: &DecodedImage, angle_hint: Option<f64>) -> (DecodedImage, f64) {
+    let width = image.width as usize;
+    let height = image.height as usize;
+
+    let grayscale = to_grayscale_values(image);
+    let threshold = otsu_threshold(&grayscale);
+    let binary: Vec<bool> = grayscale.iter().map(|&v| v < threshold).collect();
+
+    let skew_angle = estimate_skew_angle(&binary, width, height, angle_hint);
+    // `rotate_binary` 和 `rotate_image` 采用同一套采样方式，让 `rotate_binary` 的
+    // 投影直方图方差最大的角度，正是 `rotate_image` 能把图片摆正的角度，这里直接
+    // 按该角度旋正即可，不需要取反
+    let upright = rotate_image(image, skew_angle);
+
+    (upright, skew_angle)
+}
+
+fn estimate_skew_angle(binary: &[bool], width: usize, height: usize, hint: Option<f64>) -> f64 {
+    let (low, high, step) = match hint {
+        Some(h) => (h - NARROW_SEARCH_RANGE, h + NARROW_SEARCH_RANGE, NARROW_SEARCH_STEP),
+        None => (-WIDE_SEARCH_RANGE, WIDE_SEARCH_RANGE, WIDE_SEARCH_STEP),
+    };
+
+    let mut best_angle = 0.0;
+    let mut best_score = f64::MIN;
+
+    let mut angle = low;
+    while angle <= high {
+        let score = projection_score(binary, width, height, angle);
+        if score > best_score {
+            best_score = score;
+            best_angle = angle;
+        }
+        angle += step;
+    }
+
+    best_angle
+}
+
+/// 按给定角度旋转二值图后，计算相邻行前景像素计数之间差的平方和
+fn projection_score(binary: &[bool], width: usize, height: usize, angle_degrees: f64) -> f64 {
+    let rotated = rotate_binary(binary, width, height, angle_degrees);
+
+    let row_sums: Vec<u32> = (0..height)
+        .map(|y| (0..width).filter(|&x| rotated[y * width + x]).count() as u32)
+        .collect();
+
+    row_sums
+        .windows(2)
+        .map(|pair| {
+            let diff = pair[1] as f64 - pair[0] as f64;
+            diff * diff
+        })
+        .sum()
+}
+
+fn rotate_binary(binary: &[bool], width: usize, height: usize, angle_degrees: f64) -> Vec<bool> {
+    let angle = angle_degrees.to_radians();
+    let (sin_a, cos_a) = (angle.sin(), angle.cos());
+    let (cx, cy) = (width as f64 / 2.0, height as f64 / 2.0);
+
+    let mut output = vec![false; binary.len()];
+    for y in 0..height {
+        for x in 0..width {
+            let dx = x as f64 - cx;
+            let dy = y as f64 - cy;
+            // 按 -angle 在源图中采样，等价于把目标图旋转了 +angle
+            let src_x = cx + dx * cos_a + dy * sin_a;
+            let src_y = cy - dx * sin_a + dy * cos_a;
+
+            if src_x >= 0.0 && src_y >= 0.0 && (src_x as usize) < width && (src_y as usize) < height {
+                output[y * width + x] = binary[src_y as usize * width + src_x as usize];
+            }
+        }
+    }
+
+    output
+}
+
+/// 以图像中心为原点旋转整张 RGBA 图片，画布大小保持不变，越出边界的区域填充为透明
+fn rotate_image(image: &DecodedImage, angle_degrees: f64) -> DecodedImage {
+    let width = image.width as usize;
+    let height = image.height as usize;
+    let angle = angle_degrees.to_radians();
+    let (sin_a, cos_a) = (angle.sin(), angle.cos());
+    let (cx, cy) = (width as f64 / 2.0, height as f64 / 2.0);
+
+    let mut pixels = vec![0u8; image.pixels.len()];
+    for y in 0..height {
+        for x in 0..width {
+            let dx = x as f64 - cx;
+            let dy = y as f64 - cy;
+            let src_x = cx + dx * cos_a + dy * sin_a;
+            let src_y = cy - dx * sin_a + dy * cos_a;
+
+            if src_x >= 0.0 && src_y >= 0.0 && (src_x as usize) < width && (src_y as usize) < height {
+                let src_idx = (src_y as usize * width + src_x as usize) * 4;
+                let dst_idx = (y * width + x) * 4;
+                pixels[dst_idx..dst_idx + 4].copy_from_slice(&image.pixels[src_idx..src_idx + 4]);
+            }
+        }
+    }
+
+    DecodedImage {
+        width: image.width,
+        height: image.height,
+        pixels,
+    }
+}
@@ -0,0 +1,111 @@
+use crate::OcrRecognitionResult;
+
+impl OcrRecognitionResult {
+    /// 导出为 hOCR（带边界框信息的 HTML），`ocr_line`/`ocrx_word` 的
+    /// `title` 属性携带 `bbox x0 y0 x1 y1`
+    pub fn to_hocr(&self) -> String {
+        let mut out = String::new();
+        out.push_str("<!DOCTYPE html>\n<html>\n<head><meta charset=\"utf-8\"/><title>OCR Result</title></head>\n<body>\n");
+        out.push_str("<div class='ocr_page'>\n");
+
+        for (line_idx, line) in self.lines.iter().enumerate() {
+            let (x0, y0, x1, y1) = bbox_corners(&line.bounds);
+            out.push_str(&format!(
+                "<span class='ocr_line' id='line_{}' title='bbox {} {} {} {}'>\n",
+                line_idx, x0, y0, x1, y1
+            ));
+
+            for (word_idx, word) in line.words.iter().enumerate() {
+                let (wx0, wy0, wx1, wy1) = bbox_corners(&word.bounds);
+                out.push_str(&format!(
+                    "  <span class='ocrx_word' id='line_{}_word_{}' title='bbox {} {} {} {}'>{}</span>\n",
+                    line_idx,
+                    word_idx,
+                    wx0,
+                    wy0,
+                    wx1,
+                    wy1,
+                    escape_xml(&word.text)
+                ));
+            }
+
+            out.push_str("</span>\n");
+        }
+
+        out.push_str("</div>\n</body>\n</html>\n");
+        out
+    }
+
+    /// 导出为 ALTO XML，`TextLine`/`String` 使用 HPOS/VPOS/WIDTH/HEIGHT 表示位置
+    pub fn to_alto(&self) -> String {
+        let mut out = String::new();
+        out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<alto>\n  <Layout>\n    <Page>\n      <PrintSpace>\n        <TextBlock>\n");
+
+        for line in &self.lines {
+            out.push_str(&format!(
+                "          <TextLine HPOS=\"{}\" VPOS=\"{}\" WIDTH=\"{}\" HEIGHT=\"{}\">\n",
+                line.bounds.x, line.bounds.y, line.bounds.width, line.bounds.height
+            ));
+
+            for word in &line.words {
+                out.push_str(&format!(
+                    "            <String HPOS=\"{}\" VPOS=\"{}\" WIDTH=\"{}\" HEIGHT=\"{}\" CONTENT=\"{}\"/>\n",
+                    word.bounds.x,
+                    word.bounds.y,
+                    word.bounds.width,
+                    word.bounds.height,
+                    escape_xml(&word.text)
+                ));
+            }
+
+            out.push_str("          </TextLine>\n");
+        }
+
+        out.push_str("        </TextBlock>\n      </PrintSpace>\n    </Page>\n  </Layout>\n</alto>\n");
+        out
+    }
+
+    /// 导出为 TSV，列为 `level/left/top/width/height/text`；
+    /// 行级（level 4）和词级（level 5）记录都会输出，与 Tesseract TSV 的层级约定一致
+    pub fn to_tsv(&self) -> String {
+        let mut out = String::new();
+        out.push_str("level\tleft\ttop\twidth\theight\ttext\n");
+
+        for line in &self.lines {
+            out.push_str(&tsv_row(4, &line.bounds, &line.text));
+            for word in &line.words {
+                out.push_str(&tsv_row(5, &word.bounds, &word.text));
+            }
+        }
+
+        out
+    }
+}
+
+fn tsv_row(level: u8, bounds: &crate::BoundingBox, text: &str) -> String {
+    format!(
+        "{}\t{}\t{}\t{}\t{}\t{}\n",
+        level,
+        bounds.x,
+        bounds.y,
+        bounds.width,
+        bounds.height,
+        text.replace(['\t', '\n'], " ")
+    )
+}
+
+fn bbox_corners(bounds: &crate::BoundingBox) -> (i32, i32, i32, i32) {
+    (
+        bounds.x.round() as i32,
+        bounds.y.round() as i32,
+        (bounds.x + bounds.width).round() as i32,
+        (bounds.y + bounds.height).round() as i32,
+    )
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,122 @@
+use crate::BoundingBox;
+
+/// 与平台无关的已解码位图，供各 OCR 后端和图像处理流程共享
+///
+/// 像素按行主序排列，固定为 RGBA8 格式
+#[derive(Debug, Clone)]
+pub struct DecodedImage {
+    /// 图像宽度（像素）
+    pub width: u32,
+    /// 图像高度（像素）
+    pub height: u32,
+    /// RGBA8 像素数据，长度为 `width * height * 4`
+    pub pixels: Vec<u8>,
+}
+
+impl DecodedImage {
+    /// 从内存中的图片字节解码（支持 PNG、JPG、BMP 等常见格式）
+    pub fn from_bytes(data: &[u8]) -> Result<Self, String> {
+        let img = image::load_from_memory(data).map_err(|e| format!("图片解码失败: {}", e))?;
+        let rgba = img.to_rgba8();
+        let (width, height) = rgba.dimensions();
+        Ok(Self {
+            width,
+            height,
+            pixels: rgba.into_raw(),
+        })
+    }
+
+    /// 从文件路径解码图片
+    pub fn from_file(path: &str) -> Result<Self, String> {
+        let img = image::open(path).map_err(|e| format!("图片解码失败: {}", e))?;
+        let rgba = img.to_rgba8();
+        let (width, height) = rgba.dimensions();
+        Ok(Self {
+            width,
+            height,
+            pixels: rgba.into_raw(),
+        })
+    }
+
+    /// 取出一个矩形区域的拷贝，超出边界的部分会被裁剪到图像范围内
+    pub fn crop(&self, rect: &BoundingBox) -> DecodedImage {
+        let x0 = (rect.x.max(0.0) as u32).min(self.width);
+        let y0 = (rect.y.max(0.0) as u32).min(self.height);
+        let x1 = ((rect.x + rect.width).max(0.0) as u32).min(self.width);
+        let y1 = ((rect.y + rect.height).max(0.0) as u32).min(self.height);
+
+        let width = x1.saturating_sub(x0);
+        let height = y1.saturating_sub(y0);
+
+        let mut pixels = Vec::with_capacity((width * height * 4) as usize);
+        for y in y0..y1 {
+            let row_start = ((y * self.width + x0) * 4) as usize;
+            let row_end = row_start + (width * 4) as usize;
+            pixels.extend_from_slice(&self.pixels[row_start..row_end]);
+        }
+
+        DecodedImage {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// 双线性插值缩放到指定宽高，供需要固定输入尺寸的推理后端使用
+    pub(crate) fn resize(&self, new_width: u32, new_height: u32) -> DecodedImage {
+        if new_width == self.width && new_height == self.height {
+            return self.clone();
+        }
+
+        let new_width = new_width.max(1);
+        let new_height = new_height.max(1);
+        let scale_x = self.width as f32 / new_width as f32;
+        let scale_y = self.height as f32 / new_height as f32;
+
+        let mut pixels = vec![0u8; (new_width * new_height * 4) as usize];
+        for y in 0..new_height {
+            for x in 0..new_width {
+                let src_x = ((x as f32 + 0.5) * scale_x - 0.5).max(0.0);
+                let src_y = ((y as f32 + 0.5) * scale_y - 0.5).max(0.0);
+
+                let x0 = (src_x as u32).min(self.width - 1);
+                let y0 = (src_y as u32).min(self.height - 1);
+                let x1 = (x0 + 1).min(self.width - 1);
+                let y1 = (y0 + 1).min(self.height - 1);
+
+                let fx = src_x - x0 as f32;
+                let fy = src_y - y0 as f32;
+
+                let dst_idx = ((y * new_width + x) * 4) as usize;
+                for c in 0..4usize {
+                    let p00 = self.pixels[((y0 * self.width + x0) * 4) as usize + c] as f32;
+                    let p10 = self.pixels[((y0 * self.width + x1) * 4) as usize + c] as f32;
+                    let p01 = self.pixels[((y1 * self.width + x0) * 4) as usize + c] as f32;
+                    let p11 = self.pixels[((y1 * self.width + x1) * 4) as usize + c] as f32;
+
+                    let top = p00 * (1.0 - fx) + p10 * fx;
+                    let bottom = p01 * (1.0 - fx) + p11 * fx;
+                    pixels[dst_idx + c] = (top * (1.0 - fy) + bottom * fy).round() as u8;
+                }
+            }
+        }
+
+        DecodedImage {
+            width: new_width,
+            height: new_height,
+            pixels,
+        }
+    }
+
+    /// 将图像重新编码为 PNG 字节，供需要容器格式输入的后端使用
+    pub fn to_png_bytes(&self) -> Result<Vec<u8>, String> {
+        let buffer = image::RgbaImage::from_raw(self.width, self.height, self.pixels.clone())
+            .ok_or_else(|| "像素缓冲区大小与宽高不匹配".to_string())?;
+
+        let mut out = Vec::new();
+        image::DynamicImage::ImageRgba8(buffer)
+            .write_to(&mut std::io::Cursor::new(&mut out), image::ImageFormat::Png)
+            .map_err(|e| format!("PNG 编码失败: {}", e))?;
+        Ok(out)
+    }
+}
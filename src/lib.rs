@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
+#[cfg(windows)]
 use std::path::Path;
+#[cfg(windows)]
 use windows::{
     core::HSTRING,
     Globalization::Language,
@@ -8,6 +10,33 @@ use windows::{
     Storage::{FileAccessMode, StorageFile},
 };
 
+mod annotate;
+mod backend;
+mod deskew;
+mod export;
+mod image;
+#[cfg(windows)]
+mod multi;
+mod preprocess;
+#[cfg(windows)]
+mod region;
+
+pub use annotate::{render_annotated, AnnotateLevel, AnnotateOptions};
+pub use backend::{OcrBackend, PpOcrBackend, PpOcrModelPaths};
+#[cfg(windows)]
+pub use backend::WindowsOcrBackend;
+pub use deskew::deskew;
+#[cfg(windows)]
+pub use deskew::{recognize_from_bytes_with_deskew, recognize_from_file_with_deskew};
+pub use image::DecodedImage;
+#[cfg(windows)]
+pub use multi::{recognize_multi, recognize_multi_from_bytes};
+pub use preprocess::PreprocessOptions;
+#[cfg(windows)]
+pub use preprocess::{recognize_from_bytes_with_preprocessing, recognize_from_file_with_preprocessing};
+#[cfg(windows)]
+pub use region::{recognize_region, recognize_region_from_bytes};
+
 /// OCR 识别的文字行
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OcrLine {
@@ -95,6 +124,7 @@ pub struct OcrRecognitionResult {
 /// # 参数
 /// - `image_path` - 图片文件路径
 /// - `language` - 语言代码（如 "zh-Hans-CN", "en-US"），None 使用系统默认语言
+#[cfg(windows)]
 pub fn recognize_from_file(image_path: &str, language: Option<&str>) -> Result<OcrRecognitionResult, String> {
     let file_path = Path::new(image_path);
     if !file_path.exists() {
@@ -110,11 +140,13 @@ pub fn recognize_from_file(image_path: &str, language: Option<&str>) -> Result<O
 /// # 参数
 /// - `image_data` - 图片字节数据（支持 PNG、JPG、BMP 等格式）
 /// - `language` - 语言代码（如 "zh-Hans-CN", "en-US"），None 使用系统默认语言
+#[cfg(windows)]
 pub fn recognize_from_bytes(image_data: &[u8], language: Option<&str>) -> Result<OcrRecognitionResult, String> {
     recognize_from_bytes_internal(image_data, language)
         .map_err(|e| format!("OCR 识别失败: {}", e))
 }
 
+#[cfg(windows)]
 fn recognize_from_bytes_internal(image_data: &[u8], language: Option<&str>) -> windows::core::Result<OcrRecognitionResult> {
     use windows::Storage::Streams::{DataWriter, InMemoryRandomAccessStream};
     
@@ -142,6 +174,7 @@ fn recognize_from_bytes_internal(image_data: &[u8], language: Option<&str>) -> w
     convert_ocr_result(&result)
 }
 
+#[cfg(windows)]
 fn recognize_internal(image_path: &str, language: Option<&str>) -> windows::core::Result<OcrRecognitionResult> {
     let file = StorageFile::GetFileFromPathAsync(&HSTRING::from(image_path))?.get()?;
     let stream = file.OpenAsync(FileAccessMode::Read)?.get()?;
@@ -161,7 +194,8 @@ fn recognize_internal(image_path: &str, language: Option<&str>) -> windows::core
     convert_ocr_result(&result)
 }
 
-fn convert_ocr_result(win_result: &WinOcrResult) -> windows::core::Result<OcrRecognitionResult> {
+#[cfg(windows)]
+pub(crate) fn convert_ocr_result(win_result: &WinOcrResult) -> windows::core::Result<OcrRecognitionResult> {
     let mut lines = Vec::new();
     let mut full_text = String::new();
     
@@ -243,11 +277,13 @@ fn convert_ocr_result(win_result: &WinOcrResult) -> windows::core::Result<OcrRec
 }
 
 /// 获取系统支持的 OCR 语言列表
+#[cfg(windows)]
 pub fn get_available_languages() -> Result<Vec<String>, String> {
     get_available_languages_internal()
         .map_err(|e| format!("获取可用语言失败: {}", e))
 }
 
+#[cfg(windows)]
 fn get_available_languages_internal() -> windows::core::Result<Vec<String>> {
     let languages = OcrEngine::AvailableRecognizerLanguages()?;
     let count = languages.Size()?;
@@ -0,0 +1,177 @@
+use crate::{BoundingBox, DecodedImage, OcrBackend, OcrLine, OcrRecognitionResult, OcrWord, WindowsOcrBackend};
+
+const MERGE_IOU_THRESHOLD: f32 = 0.6;
+
+/// 用多种语言识别同一张图片文件，并将结果合并为一份结果
+///
+/// 对应 Tesseract `"eng+deu"` 式多语言识别：每种语言单独创建一个
+/// `OcrEngine` 执行识别，再按词的 `BoundingBox` IoU 去重合并，适合
+/// 中英文混排等单一识别器覆盖不全的场景
+///
+/// # 参数
+/// - `image_path` - 图片文件路径
+/// - `languages` - 语言代码列表，如 `["zh-Hans-CN", "en-US"]`
+pub fn recognize_multi(image_path: &str, languages: &[&str]) -> Result<OcrRecognitionResult, String> {
+    let image = DecodedImage::from_file(image_path)?;
+    recognize_multi_internal(&image, languages)
+}
+
+/// 用多种语言识别同一张字节数组图片，并将结果合并为一份结果
+pub fn recognize_multi_from_bytes(
+    image_data: &[u8],
+    languages: &[&str],
+) -> Result<OcrRecognitionResult, String> {
+    let image = DecodedImage::from_bytes(image_data)?;
+    recognize_multi_internal(&image, languages)
+}
+
+fn recognize_multi_internal(
+    image: &DecodedImage,
+    languages: &[&str],
+) -> Result<OcrRecognitionResult, String> {
+    if languages.is_empty() {
+        return Err("languages 不能为空".to_string());
+    }
+
+    let backend = WindowsOcrBackend;
+    let mut all_words = Vec::new();
+    let mut last_error = None;
+
+    for language in languages {
+        match backend.recognize(image, Some(language)) {
+            Ok(result) => {
+                for line in result.lines {
+                    all_words.extend(line.words);
+                }
+            }
+            // 某个语言没有安装对应识别器时跳过它，而不是让整次调用失败
+            Err(e) => last_error = Some(e),
+        }
+    }
+
+    if all_words.is_empty() {
+        if let Some(e) = last_error {
+            return Err(e);
+        }
+    }
+
+    let merged_words = merge_words(all_words);
+    let lines = group_into_lines(merged_words);
+    let text = lines
+        .iter()
+        .map(|line| line.text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Ok(OcrRecognitionResult {
+        lines,
+        text,
+        text_angle: None,
+    })
+}
+
+/// 按 `BoundingBox` IoU 对多个引擎的识别结果去重：IoU 超过阈值的词只保留
+/// 文本更长（更完整）的那一个，否则两个词都保留
+fn merge_words(words: Vec<OcrWord>) -> Vec<OcrWord> {
+    let mut merged: Vec<OcrWord> = Vec::new();
+
+    for word in words {
+        match merged
+            .iter_mut()
+            .find(|existing| iou(&existing.bounds, &word.bounds) > MERGE_IOU_THRESHOLD)
+        {
+            Some(existing) => {
+                if word.text.chars().count() > existing.text.chars().count() {
+                    *existing = word;
+                }
+            }
+            None => merged.push(word),
+        }
+    }
+
+    merged
+}
+
+fn iou(a: &BoundingBox, b: &BoundingBox) -> f32 {
+    let ix0 = a.x.max(b.x);
+    let iy0 = a.y.max(b.y);
+    let ix1 = (a.x + a.width).min(b.x + b.width);
+    let iy1 = (a.y + a.height).min(b.y + b.height);
+
+    let intersection = (ix1 - ix0).max(0.0) * (iy1 - iy0).max(0.0);
+    if intersection <= 0.0 {
+        return 0.0;
+    }
+
+    let union = a.width * a.height + b.width * b.height - intersection;
+    if union <= 0.0 {
+        0.0
+    } else {
+        intersection / union
+    }
+}
+
+/// 按词的纵向中心位置把合并后的词聚合回文本行，行内再按横坐标排序
+fn group_into_lines(mut words: Vec<OcrWord>) -> Vec<OcrLine> {
+    if words.is_empty() {
+        return Vec::new();
+    }
+
+    words.sort_by(|a, b| a.bounds.y.partial_cmp(&b.bounds.y).unwrap());
+
+    let mut rows: Vec<Vec<OcrWord>> = Vec::new();
+    for word in words {
+        let center_y = word.bounds.y + word.bounds.height / 2.0;
+
+        let belongs_to_last = rows.last().map(|row| {
+            let avg_center_y: f32 =
+                row.iter().map(|w| w.bounds.y + w.bounds.height / 2.0).sum::<f32>() / row.len() as f32;
+            let avg_height: f32 = row.iter().map(|w| w.bounds.height).sum::<f32>() / row.len() as f32;
+            (center_y - avg_center_y).abs() < avg_height * 0.5
+        });
+
+        if belongs_to_last == Some(true) {
+            rows.last_mut().unwrap().push(word);
+        } else {
+            rows.push(vec![word]);
+        }
+    }
+
+    rows.into_iter()
+        .map(|mut row_words| {
+            row_words.sort_by(|a, b| a.bounds.x.partial_cmp(&b.bounds.x).unwrap());
+            let bounds = union_bounds(&row_words);
+            let text = row_words
+                .iter()
+                .map(|w| w.text.as_str())
+                .collect::<Vec<_>>()
+                .join("");
+
+            OcrLine {
+                text,
+                bounds,
+                words: row_words,
+            }
+        })
+        .collect()
+}
+
+fn union_bounds(words: &[OcrWord]) -> BoundingBox {
+    let min_x = words.iter().map(|w| w.bounds.x).fold(f32::MAX, f32::min);
+    let min_y = words.iter().map(|w| w.bounds.y).fold(f32::MAX, f32::min);
+    let max_x = words
+        .iter()
+        .map(|w| w.bounds.x + w.bounds.width)
+        .fold(f32::MIN, f32::max);
+    let max_y = words
+        .iter()
+        .map(|w| w.bounds.y + w.bounds.height)
+        .fold(f32::MIN, f32::max);
+
+    BoundingBox {
+        x: min_x,
+        y: min_y,
+        width: max_x - min_x,
+        height: max_y - min_y,
+    }
+}
@@ -0,0 +1,253 @@
+use crate::DecodedImage;
+
+#[cfg(windows)]
+use crate::{OcrBackend, OcrRecognitionResult, WindowsOcrBackend};
+
+/// OCR 识别前的图像预处理选项，每个阶段都可以单独开关
+///
+/// 典型的 OCR 前处理流程是灰度化 -> 局部对比度均衡 -> 二值化，对低质量扫描件/
+/// 照片能明显提升识别率，但不同图片适合的组合不同，所以这里把每一步都做成独立开关
+#[derive(Debug, Clone)]
+pub struct PreprocessOptions {
+    /// 是否转换为灰度图
+    pub grayscale: bool,
+    /// 是否执行 CLAHE 风格的局部对比度均衡（启用后输出必然是灰度的）
+    pub clahe: bool,
+    /// CLAHE 分块大小（像素），分块越小局部对比度越强，也越容易放大噪声
+    pub clahe_tile_size: usize,
+    /// CLAHE 直方图裁剪阈值，超出部分会被裁掉并重新分配到其它灰度级
+    pub clahe_clip_limit: usize,
+    /// 是否执行 Otsu 全局二值化（启用后输出必然是灰度的）
+    pub otsu_binarize: bool,
+}
+
+impl Default for PreprocessOptions {
+    fn default() -> Self {
+        Self {
+            grayscale: false,
+            clahe: false,
+            clahe_tile_size: 8,
+            clahe_clip_limit: 40,
+            otsu_binarize: false,
+        }
+    }
+}
+
+impl PreprocessOptions {
+    /// 按开关顺序对图像执行预处理，返回处理后的新图像
+    pub fn apply(&self, image: &DecodedImage) -> DecodedImage {
+        if !self.grayscale && !self.clahe && !self.otsu_binarize {
+            return image.clone();
+        }
+
+        let width = image.width as usize;
+        let height = image.height as usize;
+        let mut values = to_grayscale_values(image);
+
+        if self.clahe {
+            values = apply_clahe(&values, width, height, self.clahe_tile_size, self.clahe_clip_limit);
+        }
+
+        if self.otsu_binarize {
+            let threshold = otsu_threshold(&values);
+            values = values.iter().map(|&v| if v >= threshold { 255 } else { 0 }).collect();
+        }
+
+        from_grayscale_values(&values, image)
+    }
+}
+
+/// 对图片文件先执行预处理，再用默认的 Windows 引擎识别
+#[cfg(windows)]
+pub fn recognize_from_file_with_preprocessing(
+    image_path: &str,
+    options: &PreprocessOptions,
+    language: Option<&str>,
+) -> Result<OcrRecognitionResult, String> {
+    let image = DecodedImage::from_file(image_path)?;
+    let processed = options.apply(&image);
+    WindowsOcrBackend.recognize(&processed, language)
+}
+
+/// 对字节数组图片先执行预处理，再用默认的 Windows 引擎识别
+#[cfg(windows)]
+pub fn recognize_from_bytes_with_preprocessing(
+    image_data: &[u8],
+    options: &PreprocessOptions,
+    language: Option<&str>,
+) -> Result<OcrRecognitionResult, String> {
+    let image = DecodedImage::from_bytes(image_data)?;
+    let processed = options.apply(&image);
+    WindowsOcrBackend.recognize(&processed, language)
+}
+
+pub(crate) fn to_grayscale_values(image: &DecodedImage) -> Vec<u8> {
+    image
+        .pixels
+        .chunks_exact(4)
+        .map(|p| (0.299 * p[0] as f32 + 0.587 * p[1] as f32 + 0.114 * p[2] as f32).round() as u8)
+        .collect()
+}
+
+fn from_grayscale_values(values: &[u8], original: &DecodedImage) -> DecodedImage {
+    let mut pixels = Vec::with_capacity(values.len() * 4);
+    for (i, &v) in values.iter().enumerate() {
+        pixels.push(v);
+        pixels.push(v);
+        pixels.push(v);
+        pixels.push(original.pixels[i * 4 + 3]);
+    }
+
+    DecodedImage {
+        width: original.width,
+        height: original.height,
+        pixels,
+    }
+}
+
+/// CLAHE：将图像切成 `tile_size x tile_size` 的小块，每块单独做直方图均衡
+/// （裁剪后重新分配），再对相邻四块的映射表做双线性插值，避免分块边界处出现突变
+fn apply_clahe(values: &[u8], width: usize, height: usize, tile_size: usize, clip_limit: usize) -> Vec<u8> {
+    let tile_size = tile_size.max(1);
+    let tiles_x = width.div_ceil(tile_size).max(1);
+    let tiles_y = height.div_ceil(tile_size).max(1);
+
+    // 每个分块一张 256 级灰度映射表
+    let mut tile_mappings = vec![vec![0u8; 256]; tiles_x * tiles_y];
+
+    for ty in 0..tiles_y {
+        for tx in 0..tiles_x {
+            let x0 = tx * tile_size;
+            let y0 = ty * tile_size;
+            let x1 = (x0 + tile_size).min(width);
+            let y1 = (y0 + tile_size).min(height);
+
+            let mut histogram = [0u32; 256];
+            for y in y0..y1 {
+                for x in x0..x1 {
+                    histogram[values[y * width + x] as usize] += 1;
+                }
+            }
+
+            tile_mappings[ty * tiles_x + tx] = build_clahe_mapping(&mut histogram, clip_limit);
+        }
+    }
+
+    let mut output = vec![0u8; values.len()];
+    for y in 0..height {
+        for x in 0..width {
+            // 当前像素在其所属分块内的相对位置，用于在四个最近分块的映射表之间插值
+            let tx_f = (x as f32 / tile_size as f32 - 0.5).max(0.0);
+            let ty_f = (y as f32 / tile_size as f32 - 0.5).max(0.0);
+
+            let tx0 = (tx_f as usize).min(tiles_x - 1);
+            let ty0 = (ty_f as usize).min(tiles_y - 1);
+            let tx1 = (tx0 + 1).min(tiles_x - 1);
+            let ty1 = (ty0 + 1).min(tiles_y - 1);
+
+            let fx = tx_f - tx0 as f32;
+            let fy = ty_f - ty0 as f32;
+
+            let v = values[y * width + x];
+            let m00 = tile_mappings[ty0 * tiles_x + tx0][v as usize] as f32;
+            let m10 = tile_mappings[ty0 * tiles_x + tx1][v as usize] as f32;
+            let m01 = tile_mappings[ty1 * tiles_x + tx0][v as usize] as f32;
+            let m11 = tile_mappings[ty1 * tiles_x + tx1][v as usize] as f32;
+
+            let top = m00 * (1.0 - fx) + m10 * fx;
+            let bottom = m01 * (1.0 - fx) + m11 * fx;
+            output[y * width + x] = (top * (1.0 - fy) + bottom * fy).round() as u8;
+        }
+    }
+
+    output
+}
+
+/// 裁剪直方图中超过 `clip_limit` 的计数，把裁掉的部分均匀重新分配到所有灰度级，
+/// 再对结果做累积分布得到该分块的灰度映射表
+fn build_clahe_mapping(histogram: &mut [u32; 256], clip_limit: usize) -> Vec<u8> {
+    let clip_limit = clip_limit as u32;
+    let mut clipped = 0u32;
+
+    for count in histogram.iter_mut() {
+        if *count > clip_limit {
+            clipped += *count - clip_limit;
+            *count = clip_limit;
+        }
+    }
+
+    let redistribute = clipped / 256;
+    let mut remainder = clipped % 256;
+    let step = if remainder > 0 { (256 / remainder) as usize } else { 1 };
+    for (level, count) in histogram.iter_mut().enumerate() {
+        *count += redistribute;
+        // 除不尽的余数按固定间隔逐级各补 1，保证裁掉的计数全部回到直方图中
+        if remainder > 0 && level % step == 0 {
+            *count += 1;
+            remainder -= 1;
+        }
+    }
+
+    let total: u32 = histogram.iter().sum();
+    let mut mapping = vec![0u8; 256];
+    let mut cumulative = 0u32;
+    for (level, &count) in histogram.iter().enumerate() {
+        cumulative += count;
+        mapping[level] = if total == 0 {
+            level as u8
+        } else {
+            ((cumulative as f32 / total as f32) * 255.0).round() as u8
+        };
+    }
+
+    mapping
+}
+
+/// Otsu 全局二值化阈值：在 256 级直方图上枚举阈值 `t`，
+/// 取使类间方差 `w0(t)*w1(t)*(mu0(t)-mu1(t))^2` 最大的 `t`
+pub(crate) fn otsu_threshold(values: &[u8]) -> u8 {
+    let mut histogram = [0u32; 256];
+    for &v in values {
+        histogram[v as usize] += 1;
+    }
+
+    let total = values.len() as f64;
+    if total == 0.0 {
+        return 128;
+    }
+
+    let sum_all: f64 = histogram
+        .iter()
+        .enumerate()
+        .map(|(level, &count)| level as f64 * count as f64)
+        .sum();
+
+    let mut weight_bg = 0.0;
+    let mut sum_bg = 0.0;
+    let mut best_variance = 0.0;
+    let mut best_threshold = 0u8;
+
+    for (level, &count) in histogram.iter().enumerate() {
+        weight_bg += count as f64;
+        if weight_bg == 0.0 {
+            continue;
+        }
+
+        let weight_fg = total - weight_bg;
+        if weight_fg <= 0.0 {
+            break;
+        }
+
+        sum_bg += level as f64 * count as f64;
+        let mean_bg = sum_bg / weight_bg;
+        let mean_fg = (sum_all - sum_bg) / weight_fg;
+
+        let between_variance = weight_bg * weight_fg * (mean_bg - mean_fg).powi(2);
+        if between_variance > best_variance {
+            best_variance = between_variance;
+            best_threshold = level as u8;
+        }
+    }
+
+    best_threshold
+}
@@ -0,0 +1,54 @@
+use crate::{BoundingBox, DecodedImage, OcrBackend, OcrRecognitionResult, WindowsOcrBackend};
+
+/// 仅对图片文件中的指定矩形区域执行 OCR 识别
+///
+/// # 参数
+/// - `image_path` - 图片文件路径
+/// - `rect` - 要识别的区域，坐标以原图左上角为原点
+/// - `language` - 语言代码，`None` 使用系统默认语言
+pub fn recognize_region(
+    image_path: &str,
+    rect: BoundingBox,
+    language: Option<&str>,
+) -> Result<OcrRecognitionResult, String> {
+    let image = DecodedImage::from_file(image_path)?;
+    recognize_region_internal(&image, rect, language)
+}
+
+/// 仅对字节数组图片中的指定矩形区域执行 OCR 识别
+pub fn recognize_region_from_bytes(
+    image_data: &[u8],
+    rect: BoundingBox,
+    language: Option<&str>,
+) -> Result<OcrRecognitionResult, String> {
+    let image = DecodedImage::from_bytes(image_data)?;
+    recognize_region_internal(&image, rect, language)
+}
+
+fn recognize_region_internal(
+    image: &DecodedImage,
+    rect: BoundingBox,
+    language: Option<&str>,
+) -> Result<OcrRecognitionResult, String> {
+    let cropped = image.crop(&rect);
+
+    // `crop` 会把请求矩形的起点钳制到图像范围内，这里用同样的钳制结果
+    // 把识别出的坐标从裁剪图偏移回原图坐标系
+    let offset_x = (rect.x.max(0.0) as u32).min(image.width) as f32;
+    let offset_y = (rect.y.max(0.0) as u32).min(image.height) as f32;
+
+    let mut result = WindowsOcrBackend.recognize(&cropped, language)?;
+    for line in &mut result.lines {
+        offset_bounds(&mut line.bounds, offset_x, offset_y);
+        for word in &mut line.words {
+            offset_bounds(&mut word.bounds, offset_x, offset_y);
+        }
+    }
+
+    Ok(result)
+}
+
+fn offset_bounds(bounds: &mut BoundingBox, dx: f32, dy: f32) {
+    bounds.x += dx;
+    bounds.y += dy;
+}